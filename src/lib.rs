@@ -67,7 +67,7 @@
 use core::{
     cmp, fmt,
     mem::{self, ManuallyDrop, MaybeUninit},
-    ops::{Deref, DerefMut},
+    ops::{ControlFlow, Deref, DerefMut, Range},
     ptr, slice,
 };
 
@@ -184,6 +184,61 @@ impl<T, const N: usize> From<[T; N]> for ArrayBuilder<T, N> {
     }
 }
 
+impl<T, const N: usize> IntoIterator for ArrayBuilder<T, N> {
+    type Item = T;
+    type IntoIter = ArrayBuilderIntoIter<T, N>;
+
+    /// Creates an owned, consuming iterator over the initialized values in
+    /// the ArrayBuilder
+    ///
+    /// ```
+    /// use array_builder::ArrayBuilder;
+    /// let mut builder: ArrayBuilder<usize, 4> = ArrayBuilder::new();
+    /// builder.push(1);
+    /// builder.push(2);
+    /// builder.push(3);
+    /// let mut iter = builder.into_iter();
+    /// assert_eq!(Some(1), iter.next());
+    /// assert_eq!(Some(3), iter.next_back());
+    /// assert_eq!(Some(2), iter.next());
+    /// assert_eq!(None, iter.next());
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        let me = ManuallyDrop::new(self);
+        let buf = unsafe { ptr::read(&me.buf) };
+        ArrayBuilderIntoIter {
+            buf,
+            alive: 0..me.len,
+        }
+    }
+}
+
+impl<T, const N: usize> Extend<T> for ArrayBuilder<T, N> {
+    /// Pushes values from the iterator onto the ArrayBuilder until it is
+    /// full, silently dropping any excess.
+    ///
+    /// Because `len` is only ever incremented after a value has been
+    /// written, a panic partway through the source iterator leaves the
+    /// ArrayBuilder's `Drop` impl with exactly the initialized elements to
+    /// clean up: nothing more, nothing less.
+    ///
+    /// ```
+    /// use array_builder::ArrayBuilder;
+    /// let mut builder: ArrayBuilder<usize, 4> = ArrayBuilder::new();
+    /// builder.extend(0..10);
+    /// assert_eq!(Ok([0, 1, 2, 3]), builder.build());
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+        while self.len < N {
+            match iter.next() {
+                Some(t) => unsafe { self.push_unchecked(t) },
+                None => break,
+            }
+        }
+    }
+}
+
 impl<T, const N: usize> ArrayBuilder<T, N> {
     const UNINIT: MaybeUninit<T> = MaybeUninit::uninit();
 
@@ -195,6 +250,52 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
         }
     }
 
+    /// Creates a `[T; N]` where each element `T` is the returned value from
+    /// `cb` using that element's index.
+    ///
+    /// Builds into a fresh ArrayBuilder, pushing `cb(i)` for every
+    /// `i in 0..N`.
+    ///
+    /// ```
+    /// use array_builder::ArrayBuilder;
+    /// let array: [usize; 5] = ArrayBuilder::from_fn(|i| i * i);
+    /// assert_eq!(array, [0, 1, 4, 9, 16]);
+    /// ```
+    pub fn from_fn(mut cb: impl FnMut(usize) -> T) -> [T; N] {
+        let mut builder = Self::new();
+        for i in 0..N {
+            builder.push(cb(i));
+        }
+        builder.build().ok().unwrap()
+    }
+
+    /// Creates a `[T; N]` where each element `T` is the returned value from
+    /// `cb` using that element's index, short-circuiting on the first
+    /// `Err`.
+    ///
+    /// If `cb` returns an `Err`, the already-initialized elements are
+    /// dropped by the intermediate ArrayBuilder and the error is returned;
+    /// there is no manual cleanup to perform.
+    ///
+    /// ```
+    /// use array_builder::ArrayBuilder;
+    /// let array: Result<[usize; 5], &str> = ArrayBuilder::try_from_fn(|i| {
+    ///     if i == 3 {
+    ///         Err("oops")
+    ///     } else {
+    ///         Ok(i * i)
+    ///     }
+    /// });
+    /// assert_eq!(array, Err("oops"));
+    /// ```
+    pub fn try_from_fn<E>(mut cb: impl FnMut(usize) -> Result<T, E>) -> Result<[T; N], E> {
+        let mut builder = Self::new();
+        for i in 0..N {
+            builder.push(cb(i)?);
+        }
+        Ok(builder.build().ok().unwrap())
+    }
+
     /// Get the number of initialized values in the ArrayBuilder
     pub fn len(&self) -> usize {
         self.len
@@ -287,6 +388,33 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
         ptr::read(self.as_ptr().add(self.len))
     }
 
+    /// Builds an ArrayBuilder by pulling values from `iter`, filling as
+    /// many slots as it yields, up to `N`. If `iter` yields fewer than `N`
+    /// items, the returned ArrayBuilder is left incomplete.
+    ///
+    /// ```
+    /// use array_builder::ArrayBuilder;
+    /// let builder: ArrayBuilder<usize, 4> = ArrayBuilder::from_iter_partial(0..2);
+    /// assert_eq!(&[0, 1], &*builder);
+    /// ```
+    pub fn from_iter_partial(iter: impl IntoIterator<Item = T>) -> Self {
+        let mut builder = Self::new();
+        builder.extend(iter);
+        builder
+    }
+
+    /// Collects the first `N` items of `iter` into a `[T; N]`, returning
+    /// `None` if the iterator yields fewer than `N` items.
+    ///
+    /// ```
+    /// use array_builder::ArrayBuilder;
+    /// assert_eq!(Some([0, 1, 2, 3]), ArrayBuilder::collect_array(0..10));
+    /// assert_eq!(None::<[usize; 4]>, ArrayBuilder::collect_array(0..2));
+    /// ```
+    pub fn collect_array(iter: impl IntoIterator<Item = T>) -> Option<[T; N]> {
+        Self::from_iter_partial(iter).build().ok()
+    }
+
     /// Converts the ArrayBuilder into a [T; N].
     /// If the ArrayBuilder is not full, returns Err(self)
     pub fn build(self) -> Result<[T; N], Self> {
@@ -320,4 +448,197 @@ impl<T, const N: usize> ArrayBuilder<T, N> {
     pub fn take(&mut self) -> Self {
         mem::replace(self, Self::new())
     }
+
+    /// Drains the initialized values out of the ArrayBuilder, passing each
+    /// one to `f`, leaving the ArrayBuilder empty.
+    ///
+    /// If `f` returns [`ControlFlow::Break`], draining stops early and the
+    /// break value is returned; any values not yet handed to `f` are
+    /// dropped. If `f` panics, the same holds: already-yielded values are
+    /// gone, not-yet-yielded values are dropped during unwinding, and
+    /// nothing is double-dropped or leaked.
+    ///
+    /// ```
+    /// use array_builder::ArrayBuilder;
+    /// use std::ops::ControlFlow;
+    ///
+    /// let mut builder: ArrayBuilder<usize, 4> = [1, 2, 3, 4].into();
+    /// let mut seen = Vec::new();
+    /// let stopped_at = builder.drain_with(|t| {
+    ///     if t == 3 {
+    ///         ControlFlow::Break(t)
+    ///     } else {
+    ///         seen.push(t);
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// });
+    /// assert_eq!(seen, vec![1, 2]);
+    /// assert_eq!(stopped_at, Some(3));
+    /// assert!(builder.is_empty());
+    /// ```
+    pub fn drain_with<R>(&mut self, mut f: impl FnMut(T) -> ControlFlow<R>) -> Option<R> {
+        // Walks the initialized prefix in place with a cursor, rather than
+        // handing off to an owned `IntoIter`, so no `[MaybeUninit<T>; N]`
+        // buffer ever gets moved. `Guard` remembers how far the cursor has
+        // gotten; its `Drop` clears the ArrayBuilder by dropping exactly the
+        // not-yet-yielded tail, whether we get here via a `Break` or a panic
+        // unwinding out of `f`.
+        struct Guard<'a, T, const N: usize> {
+            builder: &'a mut ArrayBuilder<T, N>,
+            cursor: usize,
+        }
+
+        impl<T, const N: usize> Drop for Guard<'_, T, N> {
+            fn drop(&mut self) {
+                let remaining = self.builder.len - self.cursor;
+                unsafe {
+                    let tail = self.builder.as_mut_ptr().add(self.cursor);
+                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(tail, remaining));
+                }
+                self.builder.len = 0;
+            }
+        }
+
+        let len = self.len;
+        let mut guard = Guard {
+            builder: self,
+            cursor: 0,
+        };
+        while guard.cursor < len {
+            let t = unsafe { ptr::read(guard.builder.as_ptr().add(guard.cursor)) };
+            guard.cursor += 1;
+            if let ControlFlow::Break(r) = f(t) {
+                return Some(r);
+            }
+        }
+        None
+    }
+}
+
+/// An iterator that moves values out of an [`ArrayBuilder`].
+///
+/// This struct is created by the `into_iter` method on [`ArrayBuilder`]
+/// (provided by the [`IntoIterator`] trait).
+pub struct ArrayBuilderIntoIter<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    alive: Range<usize>,
+}
+
+impl<T, const N: usize> ArrayBuilderIntoIter<T, N> {
+    fn as_ptr(&self) -> *const T {
+        self.buf.as_ptr() as _
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.buf.as_mut_ptr() as _
+    }
+
+    /// Returns the remaining, not-yet-yielded values as a slice
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.as_ptr().add(self.alive.start), self.alive.len()) }
+    }
+}
+
+impl<T, const N: usize> Iterator for ArrayBuilderIntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let idx = self.alive.next()?;
+        Some(unsafe { ptr::read(self.as_ptr().add(idx)) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.alive.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ArrayBuilderIntoIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        let idx = self.alive.next_back()?;
+        Some(unsafe { ptr::read(self.as_ptr().add(idx)) })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArrayBuilderIntoIter<T, N> {}
+
+impl<T, const N: usize> Drop for ArrayBuilderIntoIter<T, N> {
+    fn drop(&mut self) {
+        let alive = self.alive.clone();
+        let ptr = self.as_mut_ptr();
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                ptr.add(alive.start),
+                alive.len(),
+            ));
+        }
+    }
+}
+
+/// An extension trait that adds [`array_chunks`] to any [`Iterator`].
+///
+/// [`array_chunks`]: ArrayChunksExt::array_chunks
+pub trait ArrayChunksExt: Iterator {
+    /// Splits the iterator into chunks of `N` elements, built without
+    /// allocating using an [`ArrayBuilder`].
+    ///
+    /// Each call to `next` on the returned [`ArrayChunks`] fills a builder
+    /// from the underlying iterator and emits it as a `[Self::Item; N]`
+    /// once full. If the underlying iterator runs dry before a chunk is
+    /// complete, iteration stops and the leftover elements are recoverable
+    /// through [`ArrayChunks::into_remainder`].
+    ///
+    /// ```
+    /// use array_builder::ArrayChunksExt;
+    ///
+    /// let mut chunks = (0..10).array_chunks::<4>();
+    /// assert_eq!(Some([0, 1, 2, 3]), chunks.next());
+    /// assert_eq!(Some([4, 5, 6, 7]), chunks.next());
+    /// assert_eq!(None, chunks.next());
+    /// assert_eq!(&[8, 9], &*chunks.into_remainder());
+    /// ```
+    fn array_chunks<const N: usize>(self) -> ArrayChunks<Self, N>
+    where
+        Self: Sized,
+    {
+        ArrayChunks {
+            builder: ArrayBuilder::new(),
+            iter: self,
+        }
+    }
+}
+
+impl<I: Iterator> ArrayChunksExt for I {}
+
+/// An iterator that yields `[I::Item; N]` chunks, built from an
+/// [`ArrayBuilder`].
+///
+/// This struct is created by [`ArrayChunksExt::array_chunks`].
+pub struct ArrayChunks<I: Iterator, const N: usize> {
+    builder: ArrayBuilder<I::Item, N>,
+    iter: I,
+}
+
+impl<I: Iterator, const N: usize> ArrayChunks<I, N> {
+    /// Returns the elements left over after the underlying iterator ran
+    /// dry before filling a final chunk.
+    pub fn into_remainder(self) -> ArrayBuilder<I::Item, N> {
+        self.builder
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for ArrayChunks<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for _ in self.builder.len()..N {
+            // If the underlying iterator runs out, we won't have enough
+            // data to return a full chunk, so bail out and leave the
+            // partial chunk in `self.builder` for `into_remainder`.
+            unsafe {
+                self.builder.push_unchecked(self.iter.next()?);
+            }
+        }
+        Some(unsafe { self.builder.take().build_unchecked() })
+    }
 }
@@ -0,0 +1,19 @@
+use array_builder::ArrayChunksExt;
+
+#[test]
+fn array_chunks_with_remainder() {
+    let mut chunks = (0..10).array_chunks::<4>();
+    assert_eq!(Some([0, 1, 2, 3]), chunks.next());
+    assert_eq!(Some([4, 5, 6, 7]), chunks.next());
+    assert_eq!(None, chunks.next());
+    assert_eq!(&[8, 9], &*chunks.into_remainder());
+}
+
+#[test]
+fn array_chunks_exact() {
+    let mut chunks = (0..8).array_chunks::<4>();
+    assert_eq!(Some([0, 1, 2, 3]), chunks.next());
+    assert_eq!(Some([4, 5, 6, 7]), chunks.next());
+    assert_eq!(None, chunks.next());
+    assert!(chunks.into_remainder().is_empty());
+}
@@ -0,0 +1,57 @@
+use array_builder::ArrayBuilder;
+use std::ops::ControlFlow;
+
+#[test]
+fn drain_with_runs_to_completion() {
+    let mut builder: ArrayBuilder<usize, 4> = [1, 2, 3, 4].into();
+    let mut seen = Vec::new();
+    let result = builder.drain_with(|t| {
+        seen.push(t);
+        ControlFlow::<()>::Continue(())
+    });
+    assert_eq!(seen, vec![1, 2, 3, 4]);
+    assert_eq!(result, None);
+    assert!(builder.is_empty());
+}
+
+#[test]
+fn drain_with_stops_early() {
+    let mut builder: ArrayBuilder<usize, 4> = [1, 2, 3, 4].into();
+    let mut seen = Vec::new();
+    let result = builder.drain_with(|t| {
+        if t == 3 {
+            ControlFlow::Break(t)
+        } else {
+            seen.push(t);
+            ControlFlow::Continue(())
+        }
+    });
+    assert_eq!(seen, vec![1, 2]);
+    assert_eq!(result, Some(3));
+    assert!(builder.is_empty());
+}
+
+#[test]
+fn drain_with_drops_remaining_on_early_break() {
+    use std::rc::Rc;
+
+    let counter = Rc::new(());
+    let mut builder: ArrayBuilder<Rc<()>, 4> = ArrayBuilder::new();
+    builder.push(counter.clone());
+    builder.push(counter.clone());
+    builder.push(counter.clone());
+    builder.push(counter.clone());
+
+    let mut count = 0;
+    builder.drain_with(|t| {
+        count += 1;
+        drop(t);
+        if count == 2 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
@@ -0,0 +1,53 @@
+use array_builder::ArrayBuilder;
+
+#[test]
+fn extend_fills_to_capacity() {
+    let mut builder: ArrayBuilder<usize, 4> = ArrayBuilder::new();
+    builder.extend(0..10);
+    assert_eq!(Ok([0, 1, 2, 3]), builder.build());
+}
+
+#[test]
+fn extend_stops_at_existing_elements() {
+    let mut builder: ArrayBuilder<usize, 4> = ArrayBuilder::new();
+    builder.push(100);
+    builder.extend(0..10);
+    assert_eq!(Ok([100, 0, 1, 2]), builder.build());
+}
+
+#[test]
+fn extend_is_panic_safe() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+
+    struct PanicsOnThird<I> {
+        inner: I,
+        count: usize,
+    }
+
+    impl<I: Iterator> Iterator for PanicsOnThird<I> {
+        type Item = I::Item;
+        fn next(&mut self) -> Option<I::Item> {
+            self.count += 1;
+            if self.count == 3 {
+                panic!("boom");
+            }
+            self.inner.next()
+        }
+    }
+
+    let counter = Rc::new(());
+    let mut builder: ArrayBuilder<Rc<()>, 4> = ArrayBuilder::new();
+    let iter = PanicsOnThird {
+        inner: std::iter::repeat_with(|| counter.clone()),
+        count: 0,
+    };
+
+    let result = catch_unwind(AssertUnwindSafe(|| builder.extend(iter)));
+    assert!(result.is_err());
+    assert_eq!(builder.len(), 2);
+    assert_eq!(Rc::strong_count(&counter), 3);
+
+    drop(builder);
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
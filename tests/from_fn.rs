@@ -0,0 +1,29 @@
+use array_builder::ArrayBuilder;
+
+#[test]
+fn from_fn_builds_array() {
+    let array: [usize; 5] = ArrayBuilder::from_fn(|i| i * i);
+    assert_eq!(array, [0, 1, 4, 9, 16]);
+}
+
+#[test]
+fn try_from_fn_ok() {
+    let array: Result<[usize; 5], &str> = ArrayBuilder::try_from_fn(|i| Ok(i * i));
+    assert_eq!(array, Ok([0, 1, 4, 9, 16]));
+}
+
+#[test]
+fn try_from_fn_short_circuits_and_drops() {
+    use std::rc::Rc;
+
+    let counter = Rc::new(());
+    let result: Result<[Rc<()>; 5], &str> = ArrayBuilder::try_from_fn(|i| {
+        if i == 3 {
+            Err("oops")
+        } else {
+            Ok(counter.clone())
+        }
+    });
+    assert_eq!(result.err(), Some("oops"));
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
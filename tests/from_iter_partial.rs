@@ -0,0 +1,23 @@
+use array_builder::ArrayBuilder;
+
+#[test]
+fn from_iter_partial_stops_early() {
+    let builder: ArrayBuilder<usize, 4> = ArrayBuilder::from_iter_partial(0..2);
+    assert_eq!(&[0, 1], &*builder);
+}
+
+#[test]
+fn from_iter_partial_fills_to_capacity() {
+    let builder: ArrayBuilder<usize, 4> = ArrayBuilder::from_iter_partial(0..10);
+    assert_eq!(Ok([0, 1, 2, 3]), builder.build());
+}
+
+#[test]
+fn collect_array_some() {
+    assert_eq!(Some([0, 1, 2, 3]), ArrayBuilder::collect_array(0..10));
+}
+
+#[test]
+fn collect_array_none_when_too_short() {
+    assert_eq!(None::<[usize; 4]>, ArrayBuilder::collect_array(0..2));
+}
@@ -0,0 +1,47 @@
+use array_builder::ArrayBuilder;
+
+#[test]
+fn into_iter_forward() {
+    let mut builder: ArrayBuilder<usize, 4> = ArrayBuilder::new();
+    builder.push(1);
+    builder.push(2);
+    builder.push(3);
+
+    let mut iter = builder.into_iter();
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(Some(1), iter.next());
+    assert_eq!(Some(2), iter.next());
+    assert_eq!(Some(3), iter.next());
+    assert_eq!(None, iter.next());
+}
+
+#[test]
+fn into_iter_backward() {
+    let builder: ArrayBuilder<usize, 4> = [1, 2, 3, 4].into();
+
+    let mut iter = builder.into_iter();
+    assert_eq!(Some(4), iter.next_back());
+    assert_eq!(Some(1), iter.next());
+    assert_eq!(Some(3), iter.next_back());
+    assert_eq!(Some(2), iter.next());
+    assert_eq!(None, iter.next());
+    assert_eq!(None, iter.next_back());
+}
+
+#[test]
+fn into_iter_drops_remaining() {
+    use std::rc::Rc;
+
+    let counter = Rc::new(());
+    let mut builder: ArrayBuilder<Rc<()>, 4> = ArrayBuilder::new();
+    builder.push(counter.clone());
+    builder.push(counter.clone());
+    builder.push(counter.clone());
+
+    let mut iter = builder.into_iter();
+    assert_eq!(Rc::strong_count(&counter), 4);
+    iter.next();
+    assert_eq!(Rc::strong_count(&counter), 3);
+    drop(iter);
+    assert_eq!(Rc::strong_count(&counter), 1);
+}